@@ -8,17 +8,30 @@
 //! measure of redundancy, some are listed below:
 //!
 //! * The traits only guarantee a stable location while no `&mut self` methods are called upon
-//! `Self` (with the exception of [`write_buffer`](trait.WriteBuffer.html#tymethod.write_buffer) in
-//! our case). This is to allow types like `Vec`, this restriction doesn't apply to `Self::Target`.
+//!   `Self` (with the exception of [`write_buffer`](trait.WriteBuffer.html#tymethod.write_buffer) in
+//!   our case). This is to allow types like `Vec`, this restriction doesn't apply to `Self::Target`.
 //!
 //! * [`ReadBuffer`] and [`WriteBuffer`] guarantee a stable location for as long as the DMA transfer
-//! occurs. Given the intrinsics of `mem::forget` and the Rust language itself, a
-//! 'static lifetime is usually required.
+//!   occurs. Given the intrinsics of `mem::forget` and the Rust language itself, a
+//!   'static lifetime is usually required.
 //!
 //! The above list is not exhaustive, for a complete set of requirements and guarantees, the
 //! documentation of each trait and method should be analyzed.
 #![no_std]
 
+mod repeat;
+mod ring;
+mod segments;
+mod slice;
+
+pub use repeat::RepeatReadBuffer;
+pub use ring::DmaRingBuffer;
+pub use segments::*;
+pub use slice::{
+    ReadBufferExt, ReadBufferHalf, ReadBufferSlice, WriteBufferExt, WriteBufferHalf,
+    WriteBufferSlice,
+};
+
 use core::{
     mem::{self, MaybeUninit},
     ops::{Deref, DerefMut},
@@ -86,6 +99,34 @@ pub unsafe trait WriteBuffer {
     unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize);
 }
 
+/// Whether the source address of a [`ReadBuffer`] should be incremented by
+/// the DMA engine as words are transferred, or left fixed at a single
+/// address.
+///
+/// This is needed for peripheral-fill transfers (e.g. clearing a framebuffer,
+/// or streaming a fixed pattern out over SPI), where the source is a single
+/// repeated word rather than `len` distinct words in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressIncrement {
+    /// The source address should be incremented by the DMA engine after each
+    /// word is read, i.e. it points at `len` distinct words in memory.
+    Incrementing,
+    /// The source address should stay fixed for the whole transfer, i.e. the
+    /// same word is read `len` times.
+    Fixed,
+}
+
+/// Trait reporting whether a [`ReadBuffer`]'s source address should be
+/// incremented by the DMA engine, or left fixed.
+///
+/// This lets a HAL configure the peripheral's source-increment bit correctly
+/// while reusing the same [`ReadBuffer`] abstraction for both normal buffers
+/// and fixed-address sources like [`RepeatReadBuffer`].
+pub trait ReadSource: ReadBuffer {
+    /// Whether the source address should be incremented as words are read.
+    const INCREMENT: AddressIncrement;
+}
+
 // Blanket implementations for common DMA buffer types.
 
 unsafe impl<B, T> ReadBuffer for B
@@ -100,6 +141,14 @@ where
     }
 }
 
+impl<B, T> ReadSource for B
+where
+    B: Deref<Target = T> + StableDeref + 'static,
+    T: ReadTarget + ?Sized,
+{
+    const INCREMENT: AddressIncrement = AddressIncrement::Incrementing;
+}
+
 unsafe impl<B, T> WriteBuffer for B
 where
     B: DerefMut<Target = T> + StableDeref + 'static,
@@ -230,7 +279,7 @@ mod tests {
         const SIZE: usize = 128;
         static mut BUF: [u8; SIZE] = [0u8; SIZE];
 
-        let (ptr, size_local) = api_write(unsafe { &mut BUF });
+        let (ptr, size_local) = api_write(unsafe { &mut *core::ptr::addr_of_mut!(BUF) });
         assert!(unsafe { (&*ptr as &dyn Any).is::<u8>() });
         assert_eq!(size_local, SIZE);
     }
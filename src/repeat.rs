@@ -0,0 +1,40 @@
+use crate::{AddressIncrement, ReadBuffer, ReadSource, Word};
+
+/// A [ReadBuffer] that repeats a single word `n` times, for peripheral-fill
+/// transfers such as clearing a framebuffer or sending a fixed pattern to
+/// SPI, where the DMA source address must not be incremented.
+///
+/// This is the buffer-abstraction equivalent of the `start_write_repeated`
+/// pattern many HAL DMA drivers expose: instead of `n` distinct words in
+/// memory, [`read_buffer`](ReadBuffer::read_buffer) always returns the same
+/// address, and [`ReadSource::INCREMENT`] reports
+/// [`AddressIncrement::Fixed`] so the HAL knows to configure the peripheral's
+/// source-increment bit accordingly.
+///
+/// The word is held behind a `&'static` reference rather than inline, since
+/// an inline value would move (and dangle any pointer handed to DMA) along
+/// with `RepeatReadBuffer` itself, which is not a `&mut self` call and so
+/// isn't ruled out by [`ReadBuffer`]'s safety requirements.
+pub struct RepeatReadBuffer<W: Word + 'static> {
+    word: &'static W,
+    n: usize,
+}
+
+impl<W: Word + 'static> RepeatReadBuffer<W> {
+    /// Create a new [RepeatReadBuffer] which reads `word` `n` times.
+    pub fn new(word: &'static W, n: usize) -> Self {
+        Self { word, n }
+    }
+}
+
+unsafe impl<W: Word + 'static> ReadBuffer for RepeatReadBuffer<W> {
+    type Word = W;
+
+    unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+        (self.word as *const W, self.n)
+    }
+}
+
+impl<W: Word + 'static> ReadSource for RepeatReadBuffer<W> {
+    const INCREMENT: AddressIncrement = AddressIncrement::Fixed;
+}
@@ -0,0 +1,264 @@
+use crate::WriteBuffer;
+
+/// A circular buffer for continuous streaming DMA, e.g. gapless audio or ADC
+/// capture, where the DMA engine fills the buffer while the CPU drains it
+/// concurrently.
+///
+/// [DmaRingBuffer] owns a backing [WriteBuffer] and exposes the full
+/// `(ptr, cap)` pair to the HAL so it can configure the peripheral for a
+/// circular DMA transfer. It tracks a CPU-side read index, `head`, and the
+/// number of unread words, `len`, which is advanced from the DMA's
+/// current-transfer-count register via [commit](DmaRingBuffer::commit).
+///
+/// # Safety invariant
+///
+/// A call to [read](DmaRingBuffer::read) must only ever copy words strictly
+/// behind the DMA's current write pointer, i.e. `commit` must have been
+/// called with an up-to-date transfer count before the corresponding words
+/// are read. `cap` must be a whole number of words.
+///
+/// # Overrun
+///
+/// If the DMA engine writes faster than the CPU drains it, `commit` can
+/// observe the write pointer having lapped `head`, i.e. unread words were
+/// overwritten before [read](DmaRingBuffer::read) ever saw them. Rather than
+/// silently reporting those words as if they were never written,
+/// [commit](DmaRingBuffer::commit) raises the sticky flag returned by
+/// [overrun](DmaRingBuffer::overrun)/[take_overrun](DmaRingBuffer::take_overrun)
+/// so the caller can detect and handle the data loss.
+///
+/// This type is lock-free and intended for a single producer (the DMA
+/// engine) and a single consumer (the CPU).
+pub struct DmaRingBuffer<T: WriteBuffer> {
+    buffer: T,
+    ptr: *mut T::Word,
+    cap: usize,
+    head: usize,
+    tail: usize,
+    len: usize,
+    overrun: bool,
+}
+
+impl<T: WriteBuffer> DmaRingBuffer<T> {
+    /// Create a new [DmaRingBuffer] backed by `buffer`.
+    pub fn new(mut buffer: T) -> Self {
+        // SAFETY: `buffer` is not touched through any other `&mut self`
+        // method afterwards, so the returned pointer stays valid for as
+        // long as `buffer` is not dropped, per `WriteBuffer`'s safety
+        // requirements.
+        let (ptr, cap) = unsafe { buffer.write_buffer() };
+        Self {
+            buffer,
+            ptr,
+            cap,
+            head: 0,
+            tail: 0,
+            len: 0,
+            overrun: false,
+        }
+    }
+
+    /// The base address of the ring, for configuring the DMA engine's
+    /// circular transfer.
+    pub fn ptr(&self) -> *mut T::Word {
+        self.ptr
+    }
+
+    /// The capacity of the ring, in words.
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Whether the DMA engine has overwritten unread words since the last
+    /// call to [take_overrun](DmaRingBuffer::take_overrun).
+    pub fn overrun(&self) -> bool {
+        self.overrun
+    }
+
+    /// Clear and return the overrun flag.
+    ///
+    /// Use this to detect and acknowledge the CPU falling behind the DMA
+    /// engine, e.g. to resynchronize a stream after data loss.
+    pub fn take_overrun(&mut self) -> bool {
+        core::mem::replace(&mut self.overrun, false)
+    }
+
+    /// Advance the write index from the DMA's current-transfer-count
+    /// register.
+    ///
+    /// `dma_ndtr` is the number of words remaining until the DMA engine
+    /// wraps back around to the start of the ring, as reported by the
+    /// peripheral (e.g. STM32's `NDTR`). It must be at most
+    /// [capacity](DmaRingBuffer::capacity).
+    ///
+    /// If the DMA has written more words than fit in the unread region
+    /// since the last call, the overrun flag is raised (see
+    /// [overrun](DmaRingBuffer::overrun)) and the unread region is clamped
+    /// to the whole ring, discarding the oldest, now-overwritten words.
+    /// Only the DMA's position is observed, so words written in whole laps
+    /// of the ring since the last call are not counted, and an overrun made
+    /// up of whole laps is not flagged.
+    pub fn commit(&mut self, dma_ndtr: usize) {
+        debug_assert!(
+            dma_ndtr <= self.cap,
+            "dma_ndtr ({}) must not exceed the ring's capacity ({})",
+            dma_ndtr,
+            self.cap
+        );
+        let dma_ndtr = dma_ndtr.min(self.cap);
+        let new_tail = if dma_ndtr == self.cap {
+            0
+        } else {
+            self.cap - dma_ndtr
+        };
+
+        let written = (new_tail + self.cap - self.tail) % self.cap;
+        let free = self.cap - self.len;
+        if written > free {
+            self.overrun = true;
+            self.len = self.cap;
+            self.head = new_tail;
+        } else {
+            self.len += written;
+        }
+        self.tail = new_tail;
+    }
+
+    /// Copy newly-filled words into `out`, advancing the read index.
+    ///
+    /// Returns the number of words copied, which is at most `out.len()`.
+    pub fn read(&mut self, out: &mut [T::Word]) -> usize {
+        let n = self.len.min(out.len());
+        if n == 0 {
+            return 0;
+        }
+
+        let first = n.min(self.cap - self.head);
+        // SAFETY: `first` and `n - first` stay within the `cap` words backed
+        // by `self.ptr`, and only cover words behind `self.tail`, which the
+        // caller has already brought up to date with `commit`.
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.ptr.add(self.head), out.as_mut_ptr(), first);
+            if n > first {
+                core::ptr::copy_nonoverlapping(self.ptr, out.as_mut_ptr().add(first), n - first);
+            }
+        }
+
+        self.head = (self.head + n) % self.cap;
+        self.len -= n;
+        n
+    }
+
+    /// Consume the [DmaRingBuffer] and return the wrapped buffer.
+    pub fn inner(self) -> T {
+        self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: usize = 8;
+
+    fn ring() -> DmaRingBuffer<&'static mut [u8; SIZE]> {
+        static mut BUF: [u8; SIZE] = [0; SIZE];
+        let buf = unsafe { &mut *core::ptr::addr_of_mut!(BUF) };
+        for (i, word) in buf.iter_mut().enumerate() {
+            *word = i as u8;
+        }
+        DmaRingBuffer::new(buf)
+    }
+
+    #[test]
+    fn read_empty_when_head_equals_tail() {
+        let mut ring = ring();
+        let mut out = [0u8; SIZE];
+        assert_eq!(ring.read(&mut out), 0);
+    }
+
+    #[test]
+    fn read_without_wrap() {
+        let mut ring = ring();
+        ring.commit(SIZE - 3);
+
+        let mut out = [0u8; SIZE];
+        assert_eq!(ring.read(&mut out), 3);
+        assert_eq!(&out[..3], &[0, 1, 2]);
+    }
+
+    #[test]
+    fn read_partial_bounded_by_out_len() {
+        let mut ring = ring();
+        ring.commit(SIZE - 3);
+
+        let mut out = [0u8; 2];
+        assert_eq!(ring.read(&mut out), 2);
+        assert_eq!(out, [0, 1]);
+
+        // The remaining committed word is still there on the next read.
+        let mut out = [0u8; 2];
+        assert_eq!(ring.read(&mut out), 1);
+        assert_eq!(out[0], 2);
+    }
+
+    #[test]
+    fn read_with_wrap_splits_into_two_copies() {
+        let mut ring = ring();
+
+        // Move `head` into the middle of the ring...
+        ring.commit(SIZE - 4);
+        let mut out = [0u8; SIZE];
+        assert_eq!(ring.read(&mut out), 4);
+        assert_eq!(ring.head, 4);
+
+        // ...then commit a write that has wrapped past the end, so
+        // `tail < head`.
+        ring.commit(SIZE - 2);
+        assert_eq!(ring.tail, 2);
+
+        let mut out = [0u8; SIZE];
+        let n = ring.read(&mut out);
+        assert_eq!(n, 6);
+        assert_eq!(&out[..n], &[4, 5, 6, 7, 0, 1]);
+        assert_eq!(ring.head, 2);
+        assert!(!ring.overrun());
+    }
+
+    #[test]
+    fn commit_without_intervening_read_accumulates() {
+        let mut ring = ring();
+        ring.commit(SIZE - 2);
+        ring.commit(SIZE - 5);
+
+        let mut out = [0u8; SIZE];
+        assert_eq!(ring.read(&mut out), 5);
+        assert_eq!(&out[..5], &[0, 1, 2, 3, 4]);
+        assert!(!ring.overrun());
+    }
+
+    #[test]
+    fn commit_flags_overrun_when_dma_laps_the_cpu() {
+        let mut ring = ring();
+        ring.commit(SIZE - 3);
+        // The DMA writes 6 more words without the CPU reading any, lapping
+        // the 3 unread words from the first commit.
+        ring.commit(SIZE - 1);
+
+        assert!(ring.overrun());
+        assert!(ring.take_overrun());
+        assert!(!ring.overrun());
+
+        // The whole ring is now reported as unread, rather than silently
+        // losing the overwritten words.
+        let mut out = [0u8; SIZE];
+        assert_eq!(ring.read(&mut out), SIZE);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not exceed the ring's capacity")]
+    fn commit_rejects_dma_ndtr_over_capacity() {
+        let mut ring = ring();
+        ring.commit(SIZE + 1);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{ReadBuffer, WriteBuffer};
+
+/// Trait for buffers that can be given to DMA for reading as a sequence of
+/// discontiguous segments, e.g. for a scatter-gather transfer driven off a
+/// descriptor list.
+///
+/// # Safety
+///
+/// The implementing type must be safe to use for DMA reads. This means:
+///
+/// - Every `(ptr, len)` pair yielded by the iterator returned from
+///   `read_segments` must reference a valid, stable buffer, with the same
+///   guarantees as [`ReadBuffer::read_buffer`].
+/// - As long as no `&mut self` method is called on the implementing object,
+///   `read_segments` must always yield the same sequence of segments if
+///   called multiple times.
+/// - The memory referenced by each segment must not be freed during the
+///   transfer it is used in as long as `self` is not dropped.
+pub unsafe trait ReadSegments {
+    type Word;
+    type Segments: Iterator<Item = (*const Self::Word, usize)>;
+
+    /// Provide the segments usable for a scatter-gather DMA read.
+    ///
+    /// # Safety
+    ///
+    /// Once this method has been called, it is unsafe to call any `&mut self`
+    /// methods on this object as long as the returned segments are in use (by
+    /// DMA).
+    unsafe fn read_segments(&self) -> Self::Segments;
+}
+
+/// Trait for buffers that can be given to DMA for writing as a sequence of
+/// discontiguous segments, e.g. for a scatter-gather transfer driven off a
+/// descriptor list.
+///
+/// # Safety
+///
+/// The implementing type must be safe to use for DMA writes. This means:
+///
+/// - Every `(ptr, len)` pair yielded by the iterator returned from
+///   `write_segments` must reference a valid, stable buffer, with the same
+///   guarantees as [`WriteBuffer::write_buffer`].
+/// - As long as no `&mut self` method, except for `write_segments`, is called
+///   on the implementing object, `write_segments` must always yield the same
+///   sequence of segments if called multiple times.
+/// - The memory referenced by each segment must not be freed during the
+///   transfer as long as `self` is not dropped.
+pub unsafe trait WriteSegments {
+    type Word;
+    type Segments: Iterator<Item = (*mut Self::Word, usize)>;
+
+    /// Provide the segments usable for a scatter-gather DMA write.
+    ///
+    /// # Safety
+    ///
+    /// Once this method has been called, it is unsafe to call any `&mut self`
+    /// methods, except for `write_segments`, on this object as long as the
+    /// returned segments are in use (by DMA).
+    unsafe fn write_segments(&mut self) -> Self::Segments;
+}
+
+unsafe impl<B: ReadBuffer> ReadSegments for B {
+    type Word = B::Word;
+    type Segments = core::iter::Once<(*const Self::Word, usize)>;
+
+    unsafe fn read_segments(&self) -> Self::Segments {
+        core::iter::once(self.read_buffer())
+    }
+}
+
+unsafe impl<B: WriteBuffer> WriteSegments for B {
+    type Word = B::Word;
+    type Segments = core::iter::Once<(*mut Self::Word, usize)>;
+
+    unsafe fn write_segments(&mut self) -> Self::Segments {
+        core::iter::once(self.write_buffer())
+    }
+}
+
+/// A [ChainedReadBuffer] chains together an array of [ReadBuffer]s and
+/// implements [ReadSegments], yielding one segment per inner buffer.
+///
+/// This allows a HAL to build a scatter-gather DMA read out of several
+/// `'static` fragments, e.g. several `&'static mut [u8]` slices, without
+/// requiring them to be contiguous in memory.
+///
+/// # Use Case
+///
+/// See [WriteBufferSlice](crate::WriteBufferSlice) for the single-segment
+/// equivalent of the problem this solves for reads.
+pub struct ChainedReadBuffer<B> {
+    inner: B,
+}
+
+impl<B: ReadBuffer, const N: usize> ChainedReadBuffer<[B; N]> {
+    /// Create a new [ChainedReadBuffer] from an array of [ReadBuffer]s.
+    pub fn new(inner: [B; N]) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the [ChainedReadBuffer] and return the wrapped array.
+    pub fn inner(self) -> [B; N] {
+        self.inner
+    }
+}
+
+unsafe impl<B: ReadBuffer, const N: usize> ReadSegments for ChainedReadBuffer<[B; N]> {
+    type Word = B::Word;
+    type Segments = core::array::IntoIter<(*const Self::Word, usize), N>;
+
+    unsafe fn read_segments(&self) -> Self::Segments {
+        IntoIterator::into_iter(core::array::from_fn(|i| self.inner[i].read_buffer()))
+    }
+}
+
+/// A [ChainedWriteBuffer] chains together an array of [WriteBuffer]s and
+/// implements [WriteSegments], yielding one segment per inner buffer.
+///
+/// This allows a HAL to build a scatter-gather DMA write out of several
+/// `'static` fragments without requiring them to be contiguous in memory.
+pub struct ChainedWriteBuffer<B> {
+    inner: B,
+}
+
+impl<B: WriteBuffer, const N: usize> ChainedWriteBuffer<[B; N]> {
+    /// Create a new [ChainedWriteBuffer] from an array of [WriteBuffer]s.
+    pub fn new(inner: [B; N]) -> Self {
+        Self { inner }
+    }
+
+    /// Consume the [ChainedWriteBuffer] and return the wrapped array.
+    pub fn inner(self) -> [B; N] {
+        self.inner
+    }
+}
+
+unsafe impl<B: WriteBuffer, const N: usize> WriteSegments for ChainedWriteBuffer<[B; N]> {
+    type Word = B::Word;
+    type Segments = core::array::IntoIter<(*mut Self::Word, usize), N>;
+
+    unsafe fn write_segments(&mut self) -> Self::Segments {
+        IntoIterator::into_iter(core::array::from_fn(|i| self.inner[i].write_buffer()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blanket_read_buffer_yields_single_segment() {
+        static BUF: [u8; 4] = [0; 4];
+
+        let expected = unsafe { (&BUF).read_buffer() };
+        let mut segments = unsafe { (&BUF).read_segments() };
+
+        assert_eq!(segments.next(), Some(expected));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn blanket_write_buffer_yields_single_segment() {
+        static mut BUF: [u8; 4] = [0; 4];
+
+        let mut buf = unsafe { &mut *core::ptr::addr_of_mut!(BUF) };
+        let expected = unsafe { buf.write_buffer() };
+        let mut segments = unsafe { buf.write_segments() };
+
+        assert_eq!(segments.next(), Some(expected));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn chained_read_buffer_yields_n_segments_in_order() {
+        static A: [u8; 2] = [0; 2];
+        static B: [u8; 3] = [0; 3];
+        static C: [u8; 1] = [0; 1];
+
+        let chained = ChainedReadBuffer::new([&A[..], &B[..], &C[..]]);
+        let mut segments = unsafe { chained.read_segments() };
+
+        assert_eq!(segments.next(), Some(unsafe { (&A[..]).read_buffer() }));
+        assert_eq!(segments.next(), Some(unsafe { (&B[..]).read_buffer() }));
+        assert_eq!(segments.next(), Some(unsafe { (&C[..]).read_buffer() }));
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn chained_write_buffer_yields_n_segments_in_order() {
+        static mut A: [u8; 2] = [0; 2];
+        static mut B: [u8; 3] = [0; 3];
+        static mut C: [u8; 1] = [0; 1];
+
+        let (a, b, c) = unsafe { (&mut A[..], &mut B[..], &mut C[..]) };
+        let a_ptr = a.as_mut_ptr();
+        let b_ptr = b.as_mut_ptr();
+        let c_ptr = c.as_mut_ptr();
+
+        let mut chained = ChainedWriteBuffer::new([a, b, c]);
+        let mut segments = unsafe { chained.write_segments() };
+
+        assert_eq!(segments.next(), Some((a_ptr, 2)));
+        assert_eq!(segments.next(), Some((b_ptr, 3)));
+        assert_eq!(segments.next(), Some((c_ptr, 1)));
+        assert_eq!(segments.next(), None);
+    }
+}
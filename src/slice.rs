@@ -226,3 +226,278 @@ unsafe impl<T: WriteBuffer> WriteBuffer for WriteBufferSlice<T> {
         (self.ptr, self.len)
     }
 }
+
+mod split {
+    use super::{ReadBufferSlice, WriteBufferSlice};
+    use crate::{ReadBuffer, WriteBuffer};
+
+    impl<T: ReadBuffer> ReadBufferSlice<T> {
+        /// Cut this [ReadBufferSlice] into two independently-owned halves at
+        /// `mid`, for e.g. ping-pong DMA where one half is handed to the DMA
+        /// engine while the CPU works the other. Call
+        /// [`ReadBufferHalf::unsplit`] on the two halves to recover the
+        /// original buffer.
+        ///
+        /// Returns `None` if `mid > self.len`.
+        pub fn split_at(self, mid: usize) -> Option<(ReadBufferHalf<T>, ReadBufferHalf<T>)> {
+            if mid > self.len {
+                return None;
+            }
+
+            let origin_ptr = self.ptr;
+            let origin_len = self.len;
+
+            Some((
+                // `inner` is parked in the left half; the right half is a
+                // plain, ownerless view. `unsplit` figures out which of the
+                // two it got back is the owner.
+                ReadBufferHalf {
+                    ptr: origin_ptr,
+                    len: mid,
+                    origin_ptr,
+                    origin_len,
+                    owner: Some(self.inner),
+                },
+                ReadBufferHalf {
+                    ptr: unsafe { origin_ptr.add(mid) },
+                    len: origin_len - mid,
+                    origin_ptr,
+                    origin_len,
+                    owner: None,
+                },
+            ))
+        }
+    }
+
+    impl<T: WriteBuffer> WriteBufferSlice<T> {
+        /// Cut this [WriteBufferSlice] into two independently-owned halves
+        /// at `mid`, for e.g. ping-pong DMA where one half is handed to the
+        /// DMA engine while the CPU works the other. Call
+        /// [`WriteBufferHalf::unsplit`] on the two halves to recover the
+        /// original buffer.
+        ///
+        /// Returns `None` if `mid > self.len`.
+        pub fn split_at(self, mid: usize) -> Option<(WriteBufferHalf<T>, WriteBufferHalf<T>)> {
+            if mid > self.len {
+                return None;
+            }
+
+            let origin_ptr = self.ptr;
+            let origin_len = self.len;
+
+            Some((
+                WriteBufferHalf {
+                    ptr: origin_ptr,
+                    len: mid,
+                    origin_ptr,
+                    origin_len,
+                    owner: Some(self.inner),
+                },
+                WriteBufferHalf {
+                    ptr: unsafe { origin_ptr.add(mid) },
+                    len: origin_len - mid,
+                    origin_ptr,
+                    origin_len,
+                    owner: None,
+                },
+            ))
+        }
+    }
+
+    /// One half of a [ReadBufferSlice] produced by
+    /// [`ReadBufferSlice::split_at`].
+    ///
+    /// The two halves returned from a single `split_at` call share ownership
+    /// of the original buffer without refcounting or heap allocation: one
+    /// half parks `T` in its `owner` field, the other carries none, and
+    /// [`ReadBufferHalf::unsplit`] checks both halves agree on where they
+    /// came from before handing the owned value back. Dropping the owning
+    /// half without unsplitting it drops the original buffer early, same as
+    /// dropping any other `ReadBuffer`.
+    pub struct ReadBufferHalf<T: ReadBuffer> {
+        ptr: *const T::Word,
+        len: usize,
+        origin_ptr: *const T::Word,
+        origin_len: usize,
+        owner: Option<T>,
+    }
+
+    unsafe impl<T: ReadBuffer> ReadBuffer for ReadBufferHalf<T> {
+        type Word = T::Word;
+
+        unsafe fn read_buffer(&self) -> (*const Self::Word, usize) {
+            (self.ptr, self.len)
+        }
+    }
+
+    impl<T: ReadBuffer> ReadBufferHalf<T> {
+        /// Recombine the two halves produced by a single
+        /// [`ReadBufferSlice::split_at`] call back into the original
+        /// buffer.
+        ///
+        /// Returns `None` if `a` and `b` were not split from the same
+        /// buffer region, are not adjacent, or don't have exactly one
+        /// owner between them. Halves from two separate `split_at` calls
+        /// on the same region at the same `mid` are not told apart.
+        pub fn unsplit(a: Self, b: Self) -> Option<T> {
+            if a.origin_ptr != b.origin_ptr || a.origin_len != b.origin_len {
+                return None;
+            }
+
+            let adjacent = unsafe { a.ptr.add(a.len) == b.ptr || b.ptr.add(b.len) == a.ptr };
+            if !adjacent {
+                return None;
+            }
+
+            match (a.owner, b.owner) {
+                (Some(owner), None) | (None, Some(owner)) => Some(owner),
+                _ => None,
+            }
+        }
+    }
+
+    /// One half of a [WriteBufferSlice] produced by
+    /// [`WriteBufferSlice::split_at`].
+    ///
+    /// The two halves returned from a single `split_at` call share ownership
+    /// of the original buffer without refcounting or heap allocation: one
+    /// half parks `T` in its `owner` field, the other carries none, and
+    /// [`WriteBufferHalf::unsplit`] checks both halves agree on where they
+    /// came from before handing the owned value back. Dropping the owning
+    /// half without unsplitting it drops the original buffer early, same as
+    /// dropping any other `WriteBuffer`.
+    pub struct WriteBufferHalf<T: WriteBuffer> {
+        ptr: *mut T::Word,
+        len: usize,
+        origin_ptr: *mut T::Word,
+        origin_len: usize,
+        owner: Option<T>,
+    }
+
+    unsafe impl<T: WriteBuffer> WriteBuffer for WriteBufferHalf<T> {
+        type Word = T::Word;
+
+        unsafe fn write_buffer(&mut self) -> (*mut Self::Word, usize) {
+            (self.ptr, self.len)
+        }
+    }
+
+    impl<T: WriteBuffer> WriteBufferHalf<T> {
+        /// Recombine the two halves produced by a single
+        /// [`WriteBufferSlice::split_at`] call back into the original
+        /// buffer.
+        ///
+        /// Returns `None` if `a` and `b` were not split from the same
+        /// buffer region, are not adjacent, or don't have exactly one
+        /// owner between them. Halves from two separate `split_at` calls
+        /// on the same region at the same `mid` are not told apart.
+        pub fn unsplit(a: Self, b: Self) -> Option<T> {
+            if a.origin_ptr != b.origin_ptr || a.origin_len != b.origin_len {
+                return None;
+            }
+
+            let adjacent = unsafe { a.ptr.add(a.len) == b.ptr || b.ptr.add(b.len) == a.ptr };
+            if !adjacent {
+                return None;
+            }
+
+            match (a.owner, b.owner) {
+                (Some(owner), None) | (None, Some(owner)) => Some(owner),
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{ReadBufferHalf, WriteBufferHalf};
+        use super::super::{ReadBufferExt, WriteBufferExt};
+
+        const SIZE: usize = 8;
+
+        fn write_buf() -> &'static mut [u8; SIZE] {
+            static mut BUF: [u8; SIZE] = [0, 1, 2, 3, 4, 5, 6, 7];
+            unsafe { &mut *core::ptr::addr_of_mut!(BUF) }
+        }
+
+        fn read_buf() -> &'static [u8; SIZE] {
+            static BUF: [u8; SIZE] = [0, 1, 2, 3, 4, 5, 6, 7];
+            &BUF
+        }
+
+        #[test]
+        fn write_split_unsplit_round_trips() {
+            let slice = write_buf().into_write_buffer_slice(..).unwrap();
+            let (a, b) = slice.split_at(3).unwrap();
+            let buf = WriteBufferHalf::unsplit(a, b).unwrap();
+            assert_eq!(*buf, [0, 1, 2, 3, 4, 5, 6, 7]);
+        }
+
+        #[test]
+        fn write_unsplit_accepts_swapped_order() {
+            let slice = write_buf().into_write_buffer_slice(..).unwrap();
+            let (a, b) = slice.split_at(3).unwrap();
+            let buf = WriteBufferHalf::unsplit(b, a).unwrap();
+            assert_eq!(*buf, [0, 1, 2, 3, 4, 5, 6, 7]);
+        }
+
+        #[test]
+        fn write_split_at_zero_and_len() {
+            let slice = write_buf().into_write_buffer_slice(..).unwrap();
+            let (a, b) = slice.split_at(0).unwrap();
+            assert_eq!(a.len, 0);
+            assert_eq!(b.len, SIZE);
+            assert!(WriteBufferHalf::unsplit(a, b).is_some());
+
+            let slice = write_buf().into_write_buffer_slice(..).unwrap();
+            let (a, b) = slice.split_at(SIZE).unwrap();
+            assert_eq!(a.len, SIZE);
+            assert_eq!(b.len, 0);
+            assert!(WriteBufferHalf::unsplit(a, b).is_some());
+        }
+
+        #[test]
+        fn write_unsplit_rejects_mismatched_pair() {
+            let slice_1 = write_buf().into_write_buffer_slice(..).unwrap();
+            let (a1, _b1) = slice_1.split_at(3).unwrap();
+
+            static mut BUF_2: [u8; SIZE] = [0; SIZE];
+            let slice_2 = unsafe { &mut *core::ptr::addr_of_mut!(BUF_2) }
+                .into_write_buffer_slice(..)
+                .unwrap();
+            let (_a2, b2) = slice_2.split_at(3).unwrap();
+
+            assert!(WriteBufferHalf::unsplit(a1, b2).is_none());
+        }
+
+        #[test]
+        fn read_split_unsplit_round_trips() {
+            let slice = read_buf().into_read_buffer_slice(..).unwrap();
+            let (a, b) = slice.split_at(5).unwrap();
+            let buf = ReadBufferHalf::unsplit(a, b).unwrap();
+            assert_eq!(*buf, [0, 1, 2, 3, 4, 5, 6, 7]);
+        }
+
+        #[test]
+        fn read_unsplit_accepts_swapped_order() {
+            let slice = read_buf().into_read_buffer_slice(..).unwrap();
+            let (a, b) = slice.split_at(5).unwrap();
+            let buf = ReadBufferHalf::unsplit(b, a).unwrap();
+            assert_eq!(*buf, [0, 1, 2, 3, 4, 5, 6, 7]);
+        }
+
+        #[test]
+        fn read_unsplit_rejects_mismatched_pair() {
+            let slice_1 = read_buf().into_read_buffer_slice(..).unwrap();
+            let (a1, _b1) = slice_1.split_at(5).unwrap();
+
+            static BUF_2: [u8; SIZE] = [0; SIZE];
+            let slice_2 = (&BUF_2).into_read_buffer_slice(..).unwrap();
+            let (_a2, b2) = slice_2.split_at(5).unwrap();
+
+            assert!(ReadBufferHalf::unsplit(a1, b2).is_none());
+        }
+    }
+}
+
+pub use split::{ReadBufferHalf, WriteBufferHalf};